@@ -0,0 +1,78 @@
+use std::fmt;
+
+use serde::de::{self, Visitor};
+use serde::forward_to_deserialize_any;
+
+use crate::value::{Value, ValueKind};
+
+/// Error produced while deserializing a [`Value`] tree into a user type.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Error(String);
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)
+    }
+}
+
+impl std::error::Error for Error {}
+
+impl de::Error for Error {
+    fn custom<T: fmt::Display>(msg: T) -> Self {
+        Error(msg.to_string())
+    }
+}
+
+impl<'de> de::Deserializer<'de> for Value {
+    type Error = Error;
+
+    fn deserialize_any<V>(self, visitor: V) -> Result<V::Value, Self::Error>
+    where
+        V: Visitor<'de>,
+    {
+        match self.kind {
+            ValueKind::Nil => visitor.visit_unit(),
+            ValueKind::Boolean(value) => visitor.visit_bool(value),
+            ValueKind::Integer(value) => visitor.visit_i64(value),
+            // `UInteger` carries values that didn't fit in an `i64` (see
+            // `from_json_value`'s `as_u64()` branch) — hand them to the
+            // visitor as a `u64` the same way `Integer` is handed off as an
+            // `i64`, rather than falling back to a lossy path.
+            ValueKind::UInteger(value) => visitor.visit_u64(value),
+            ValueKind::Float(value) => visitor.visit_f64(value),
+            ValueKind::String(value) => visitor.visit_string(value),
+            ValueKind::Array(array) => {
+                let mut deserializer = de::value::SeqDeserializer::new(array.into_iter());
+                let seq = visitor.visit_seq(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(seq)
+            }
+            ValueKind::Table(table) => {
+                let mut deserializer = de::value::MapDeserializer::new(table.into_iter());
+                let map = visitor.visit_map(&mut deserializer)?;
+                deserializer.end()?;
+                Ok(map)
+            }
+        }
+    }
+
+    forward_to_deserialize_any! {
+        bool i8 i16 i32 i64 i128 u8 u16 u32 u64 u128 f32 f64 char str string
+        bytes byte_buf option unit unit_struct newtype_struct seq tuple
+        tuple_struct map struct enum identifier ignored_any
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use serde::Deserialize;
+
+    use crate::value::{Value, ValueKind};
+
+    #[test]
+    fn uinteger_deserializes_as_u64_without_precision_loss() {
+        let value = Value::new(None, ValueKind::UInteger(u64::MAX));
+
+        assert_eq!(u64::deserialize(value).unwrap(), u64::MAX);
+    }
+}
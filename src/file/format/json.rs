@@ -1,4 +1,5 @@
 use std::error::Error;
+use std::io;
 
 use crate::map::Map;
 use crate::value::{Value, ValueKind};
@@ -8,26 +9,40 @@ pub fn parse(
     text: &str,
 ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
     // Parse a JSON object value from the text
-    // TODO: Have a proper error fire if the root of a file is ever not a Table
-    let value = from_json_value(uri, &serde_json::from_str(text)?);
-    match value.kind {
-        ValueKind::Table(map) => Ok(map),
-
-        _ => Ok(Map::new()),
-    }
+    let value = from_json_value(uri, &serde_json::from_str(text)?)?;
+    super::root_table_or_err(uri, value)
 }
 
-fn from_json_value(uri: Option<&String>, value: &serde_json::Value) -> Value {
-    match *value {
+fn from_json_value(
+    uri: Option<&String>,
+    value: &serde_json::Value,
+) -> Result<Value, Box<dyn Error + Send + Sync>> {
+    Ok(match *value {
         serde_json::Value::String(ref value) => Value::new(uri, ValueKind::String(value.clone())),
 
         serde_json::Value::Number(ref value) => {
             if let Some(value) = value.as_i64() {
                 Value::new(uri, ValueKind::Integer(value))
+            } else if let Some(value) = value.as_u64() {
+                // A positive integer too large for an i64 (e.g. a snowflake ID
+                // or a 64-bit flag mask) — keep it exact rather than losing
+                // precision through an f64 round-trip.
+                Value::new(uri, ValueKind::UInteger(value))
             } else if let Some(value) = value.as_f64() {
                 Value::new(uri, ValueKind::Float(value))
             } else {
-                unreachable!();
+                // Only reachable with the `arbitrary_precision` serde_json
+                // feature, where a number can exceed all three of i64, u64,
+                // and f64 (e.g. a literal such as `1e400`, beyond f64's finite
+                // range). Report it rather than panicking on untrusted
+                // file contents.
+                return Err(Box::new(io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!(
+                        "number {value} in {} cannot be represented as an i64, u64, or f64",
+                        uri.map(String::as_str).unwrap_or("<unknown>")
+                    ),
+                )));
             }
         }
 
@@ -37,7 +52,7 @@ fn from_json_value(uri: Option<&String>, value: &serde_json::Value) -> Value {
             let mut m = Map::new();
 
             for (key, value) in table {
-                m.insert(key.clone(), from_json_value(uri, value));
+                m.insert(key.clone(), from_json_value(uri, value)?);
             }
 
             Value::new(uri, ValueKind::Table(m))
@@ -47,12 +62,49 @@ fn from_json_value(uri: Option<&String>, value: &serde_json::Value) -> Value {
             let mut l = Vec::new();
 
             for value in array {
-                l.push(from_json_value(uri, value));
+                l.push(from_json_value(uri, value)?);
             }
 
             Value::new(uri, ValueKind::Array(l))
         }
 
         serde_json::Value::Null => Value::new(uri, ValueKind::Nil),
+    })
+}
+
+#[cfg(test)]
+mod test {
+    use super::parse;
+
+    #[test]
+    fn error_when_root_is_not_a_table() {
+        let uri = "Settings.json".to_string();
+        let err = parse(Some(&uri), "[1, 2, 3]").unwrap_err();
+
+        assert_eq!(
+            err.to_string(),
+            "expected a table at the root of \"Settings.json\" but found an array"
+        );
+    }
+
+    #[test]
+    fn ok_when_root_is_a_table() {
+        let map = parse(None, r#"{"debug": true}"#).unwrap();
+
+        assert_eq!(map.len(), 1);
+    }
+
+    #[test]
+    fn preserves_u64_too_large_for_i64() {
+        use crate::value::ValueKind;
+
+        // One above i64::MAX — as_i64() fails, as_u64() must be tried before
+        // falling back to the lossy as_f64() path.
+        let map = parse(None, r#"{"id": 9223372036854775808}"#).unwrap();
+
+        assert_eq!(
+            map.get("id").unwrap().kind,
+            ValueKind::UInteger(9223372036854775808)
+        );
     }
 }
@@ -0,0 +1,135 @@
+pub(crate) mod json;
+
+use std::error::Error;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+use crate::file::{FileStoredFormat, Format};
+use crate::map::Map;
+use crate::value::{Value, ValueKind};
+
+/// A configuration file format recognised by this crate out of the box.
+#[derive(Copy, Clone, Debug, Eq, PartialEq, Hash)]
+pub enum FileFormat {
+    Json,
+}
+
+impl FileFormat {
+    /// Extensions this format is recognised by when no explicit format hint
+    /// is given. [`ALL_EXTENSIONS`] lists the same extensions for each format.
+    pub(crate) fn extensions(&self) -> &'static [&'static str] {
+        match self {
+            FileFormat::Json => &["json"],
+        }
+    }
+}
+
+impl Format for FileFormat {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+        match self {
+            FileFormat::Json => json::parse(uri, text),
+        }
+    }
+}
+
+impl FileStoredFormat for FileFormat {
+    fn file_extensions(&self) -> &'static [&'static str] {
+        self.extensions()
+    }
+}
+
+/// Every registered [`FileFormat`] paired with the extensions it's
+/// recognised by, used to detect a file's format when none is given
+/// explicitly.
+pub(crate) static ALL_EXTENSIONS: &[(FileFormat, &[&str])] = &[(FileFormat::Json, &["json"])];
+
+/// Determines which [`Format`] to parse `path` with: `format_hint` if given,
+/// otherwise looked up from `path`'s extension against [`ALL_EXTENSIONS`].
+///
+/// Shared by [`FileSourceFile`](crate::file::source::file::FileSourceFile)
+/// and [`FileSourceGlob`](crate::file::source::glob::FileSourceGlob) so the
+/// extension-to-format lookup isn't duplicated between them.
+pub(crate) fn format_for_extension<F>(
+    path: &Path,
+    format_hint: Option<&F>,
+) -> Result<Box<dyn Format>, Box<dyn Error + Send + Sync>>
+where
+    F: FileStoredFormat + Format + Clone + 'static,
+{
+    if let Some(format) = format_hint {
+        return Ok(Box::new(format.clone()));
+    }
+
+    let ext = path.extension().unwrap_or_default().to_string_lossy();
+
+    ALL_EXTENSIONS
+        .iter()
+        .find(|(_, extensions)| extensions.contains(&ext.as_ref()))
+        .map(|(format, _)| Box::new(*format) as Box<dyn Format>)
+        .ok_or_else(|| {
+            Box::new(io::Error::new(
+                io::ErrorKind::NotFound,
+                format!(
+                    "configuration file \"{}\" is not of a registered file format",
+                    path.to_string_lossy()
+                ),
+            )) as Box<dyn Error + Send + Sync>
+        })
+}
+
+/// The root of a config file failed to parse as a table.
+#[derive(Debug)]
+pub(crate) struct RootNotTableError {
+    uri: String,
+    kind: &'static str,
+}
+
+impl fmt::Display for RootNotTableError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "expected a table at the root of \"{}\" but found {}",
+            self.uri, self.kind
+        )
+    }
+}
+
+impl Error for RootNotTableError {}
+
+/// Unwraps a parsed [`Value`] into the [`Table`](ValueKind::Table) at its
+/// root, or produces a descriptive error naming the source `uri` and the
+/// [`ValueKind`] that was found instead.
+///
+/// Every format parser expects its root to be a table, so this lives here
+/// for format modules (currently `json`) to share rather than duplicate.
+pub(crate) fn root_table_or_err(
+    uri: Option<&String>,
+    value: Value,
+) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>> {
+    match value.kind {
+        ValueKind::Table(map) => Ok(map),
+
+        kind => Err(Box::new(RootNotTableError {
+            uri: uri.cloned().unwrap_or_else(|| "<unknown>".into()),
+            kind: kind_name(&kind),
+        })),
+    }
+}
+
+fn kind_name(kind: &ValueKind) -> &'static str {
+    match kind {
+        ValueKind::Table(_) => "a table",
+        ValueKind::Array(_) => "an array",
+        ValueKind::String(_) => "a string",
+        ValueKind::Integer(_) => "an integer",
+        ValueKind::UInteger(_) => "an integer",
+        ValueKind::Float(_) => "a float",
+        ValueKind::Boolean(_) => "a boolean",
+        ValueKind::Nil => "nil",
+    }
+}
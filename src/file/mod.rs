@@ -0,0 +1,124 @@
+pub(crate) mod format;
+pub(crate) mod source;
+
+use std::error::Error;
+use std::path::PathBuf;
+
+pub use self::format::FileFormat;
+pub use self::source::file::FileSourceFile;
+pub use self::source::glob::FileSourceGlob;
+pub(crate) use self::source::FileSourceResult;
+
+use crate::map::Map;
+use crate::value::Value;
+
+/// Parses a format's source text into a table of configuration values.
+pub trait Format {
+    fn parse(
+        &self,
+        uri: Option<&String>,
+        text: &str,
+    ) -> Result<Map<String, Value>, Box<dyn Error + Send + Sync>>;
+}
+
+/// A [`Format`] that can also be recognised from a file extension, so a
+/// [`File`] source can detect it without an explicit format hint.
+pub trait FileStoredFormat: Format {
+    fn file_extensions(&self) -> &'static [&'static str];
+}
+
+/// A source that resolves to the file(s) it represents: their raw contents
+/// plus the [`Format`] to parse each with, in the order they should be
+/// layered (later entries override earlier ones).
+///
+/// [`FileSourceFile`] always resolves to exactly one file; [`FileSourceGlob`]
+/// may resolve to many, which is why `resolve` returns a `Vec` rather than a
+/// single [`FileSourceResult`].
+pub trait FileSource<F>: Clone + std::fmt::Debug
+where
+    F: FileStoredFormat,
+{
+    fn resolve(
+        &self,
+        format_hint: Option<F>,
+    ) -> Result<Vec<FileSourceResult>, Box<dyn Error + Send + Sync>>;
+}
+
+/// A configuration source backed by one or more files on disk.
+///
+/// Built via [`File::with_name`] for a single file (resolved the same way
+/// regardless of which registered extension it ends up using, and relative
+/// to the current working directory plus any [`File::search_paths`]), or
+/// [`File::from_glob`] for every file matching a pattern such as
+/// `conf.d/*.toml`, layered in sorted filename order.
+#[derive(Clone, Debug)]
+pub struct File<T, F> {
+    format: Option<F>,
+    required: bool,
+    source: T,
+}
+
+impl File<FileSourceFile, FileFormat> {
+    /// Creates a file source that looks for `name`, optionally extended with
+    /// a registered extension, relative to the current working directory.
+    pub fn with_name(name: &str) -> Self {
+        File {
+            format: None,
+            required: true,
+            source: FileSourceFile::new(name.into()),
+        }
+    }
+
+    /// Registers directories to search for the named file in, tried in order
+    /// after the current working directory. Forwards to
+    /// [`FileSourceFile::search_paths`].
+    pub fn search_paths(mut self, search_paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.source = self.source.search_paths(search_paths);
+        self
+    }
+}
+
+impl File<FileSourceGlob, FileFormat> {
+    /// Creates a file source that expands `pattern` (e.g. `conf.d/*.toml`)
+    /// into every matching file, layered in sorted filename order.
+    pub fn from_glob(pattern: &str) -> Self {
+        File {
+            format: None,
+            required: true,
+            source: FileSourceGlob::new(pattern),
+        }
+    }
+}
+
+impl<T, F> File<T, F> {
+    /// Overrides the format this source is parsed with, skipping extension
+    /// based detection.
+    pub fn format(mut self, format: F) -> Self {
+        self.format = Some(format);
+        self
+    }
+
+    /// Sets whether resolving zero files is an error. Defaults to `true`.
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+}
+
+impl<T, F> File<T, F>
+where
+    T: FileSource<F>,
+    F: FileStoredFormat + Clone,
+{
+    /// Resolves the underlying source to its file(s), in layering order.
+    ///
+    /// The builder (`ConfigBuilder::add_source`) is responsible for turning
+    /// a non-required, empty result into a no-op rather than an error.
+    pub(crate) fn resolve(&self) -> Result<Vec<FileSourceResult>, Box<dyn Error + Send + Sync>> {
+        self.source.resolve(self.format.clone())
+    }
+
+    pub(crate) fn is_required(&self) -> bool {
+        self.required
+    }
+}
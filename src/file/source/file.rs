@@ -6,7 +6,9 @@ use std::iter::Iterator;
 use std::path::{Path, PathBuf};
 
 use crate::file::{
-    format::ALL_EXTENSIONS, source::FileSourceResult, FileSource, FileStoredFormat, Format,
+    format::{format_for_extension, ALL_EXTENSIONS},
+    source::FileSourceResult,
+    FileSource, FileStoredFormat, Format,
 };
 
 /// Describes a file sourced from a file
@@ -14,67 +16,82 @@ use crate::file::{
 pub struct FileSourceFile {
     /// Path of configuration file
     name: PathBuf,
+
+    /// Additional directories to search for `name` in, tried in order after
+    /// the current working directory. Lets callers look for configuration in
+    /// e.g. `/etc/app` or `$XDG_CONFIG_HOME/app` without hard-coding a full
+    /// path into `name` itself.
+    search_paths: Vec<PathBuf>,
 }
 
 impl FileSourceFile {
     pub fn new(name: PathBuf) -> FileSourceFile {
-        FileSourceFile { name }
+        FileSourceFile {
+            name,
+            search_paths: Vec::new(),
+        }
+    }
+
+    /// Registers directories to search for the configuration file in, tried
+    /// in order after the current working directory. Has no effect if `name`
+    /// is an absolute path, since that is always used verbatim.
+    pub fn search_paths(mut self, search_paths: impl IntoIterator<Item = PathBuf>) -> Self {
+        self.search_paths = search_paths.into_iter().collect();
+        self
+    }
+
+    /// Returns, in order, the roots that `name` should be resolved against.
+    /// An absolute `name` is used verbatim and is not resolved against any
+    /// root, so this yields `None` in that case.
+    fn search_roots(&self) -> Result<Vec<Option<PathBuf>>, Box<dyn Error + Send + Sync>> {
+        if self.name.is_absolute() {
+            return Ok(vec![None]);
+        }
+
+        let mut roots = vec![env::current_dir()?];
+        roots.extend(self.search_paths.iter().cloned());
+        Ok(roots.into_iter().map(Some).collect())
     }
 
     fn find_file<F>(
         &self,
         format_hint: Option<F>,
-    ) -> Result<(PathBuf, Box<dyn Format>), Box<dyn Error + Send + Sync>>
+    ) -> Result<(PathBuf, Option<PathBuf>, Box<dyn Format>), Box<dyn Error + Send + Sync>>
     where
-        F: FileStoredFormat + Format + 'static,
+        F: FileStoredFormat + Format + Clone + 'static,
     {
-        // First check for an _exact_ match
-        let mut filename = env::current_dir()?.as_path().join(self.name.clone());
-        if filename.is_file() {
-            return match format_hint {
-                Some(format) => Ok((filename, Box::new(format))),
-                None => {
-                    for (format, extensions) in ALL_EXTENSIONS.iter() {
-                        if extensions.contains(
-                            &filename
-                                .extension()
-                                .unwrap_or_default()
-                                .to_string_lossy()
-                                .as_ref(),
-                        ) {
-                            return Ok((filename, Box::new(*format)));
-                        }
-                    }
-
-                    Err(Box::new(io::Error::new(
-                        io::ErrorKind::NotFound,
-                        format!(
-                            "configuration file \"{}\" is not of a registered file format",
-                            filename.to_string_lossy()
-                        ),
-                    )))
-                }
+        for root in self.search_roots()? {
+            let base_name = match &root {
+                Some(root) => root.join(&self.name),
+                None => self.name.clone(),
             };
-        }
 
-        match format_hint {
-            Some(format) => {
-                for ext in format.file_extensions() {
-                    filename.set_extension(ext);
-
-                    if filename.is_file() {
-                        return Ok((filename, Box::new(format)));
-                    }
-                }
+            // First check for an _exact_ match
+            let mut filename = base_name;
+            if filename.is_file() {
+                let format = format_for_extension(&filename, format_hint.as_ref())?;
+                return Ok((filename, root, format));
             }
 
-            None => {
-                for (format, extensions) in ALL_EXTENSIONS.iter() {
-                    for ext in format.extensions() {
+            match format_hint.as_ref() {
+                Some(format) => {
+                    for ext in format.file_extensions() {
                         filename.set_extension(ext);
 
                         if filename.is_file() {
-                            return Ok((filename, Box::new(*format)));
+                            return Ok((filename, root, Box::new(format.clone())));
+                        }
+                    }
+                }
+
+                None => {
+                    for (format, _) in ALL_EXTENSIONS.iter() {
+                        for ext in format.extensions() {
+                            filename.set_extension(ext);
+
+                            if filename.is_file() {
+                                return Ok((filename, root, Box::new(*format)));
+                            }
                         }
                     }
                 }
@@ -93,19 +110,19 @@ impl FileSourceFile {
 
 impl<F> FileSource<F> for FileSourceFile
 where
-    F: Format + FileStoredFormat + 'static,
+    F: Format + FileStoredFormat + Clone + 'static,
 {
     fn resolve(
         &self,
         format_hint: Option<F>,
-    ) -> Result<FileSourceResult, Box<dyn Error + Send + Sync>> {
+    ) -> Result<Vec<FileSourceResult>, Box<dyn Error + Send + Sync>> {
         // Find file
-        let (filename, format) = self.find_file(format_hint)?;
+        let (filename, root, format) = self.find_file(format_hint)?;
 
-        // Attempt to use a relative path for the URI
-        let base = env::current_dir()?;
-        let uri = match path_relative_from(&filename, &base) {
-            Some(value) => value,
+        // Attempt to use a path relative to whichever search root matched
+        // for the URI
+        let uri = match root {
+            Some(root) => path_relative_from(&filename, &root).unwrap_or_else(|| filename.clone()),
             None => filename.clone(),
         };
 
@@ -114,11 +131,11 @@ where
         let mut text = String::new();
         file.read_to_string(&mut text)?;
 
-        Ok(FileSourceResult {
+        Ok(vec![FileSourceResult {
             uri: Some(uri.to_string_lossy().into_owned()),
             content: text,
             format,
-        })
+        }])
     }
 }
 
@@ -163,3 +180,45 @@ fn path_relative_from(path: &Path, base: &Path) -> Option<PathBuf> {
         Some(comps.iter().map(|c| c.as_os_str()).collect())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::FileSourceFile;
+    use crate::file::FileFormat;
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn absolute_name_is_used_verbatim() {
+        let dir = temp_dir("file-absolute");
+        let file = dir.join("settings.json");
+        fs::write(&file, "{}").unwrap();
+
+        let source = FileSourceFile::new(file.clone());
+        let (resolved, root, _) = source.find_file::<FileFormat>(None).unwrap();
+
+        assert_eq!(resolved, file);
+        assert_eq!(root, None);
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn search_paths_are_tried_in_order_after_cwd() {
+        let first = temp_dir("file-search-first");
+        let second = temp_dir("file-search-second");
+        fs::write(second.join("settings.json"), "{}").unwrap();
+
+        let source = FileSourceFile::new(PathBuf::from("settings"))
+            .search_paths(vec![first.clone(), second.clone()]);
+        let (resolved, root, _) = source.find_file::<FileFormat>(None).unwrap();
+
+        assert_eq!(resolved, second.join("settings.json"));
+        assert_eq!(root, Some(second.clone()));
+
+        fs::remove_dir_all(&first).ok();
+        fs::remove_dir_all(&second).ok();
+    }
+}
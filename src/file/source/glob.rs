@@ -0,0 +1,115 @@
+use std::error::Error;
+use std::fs;
+use std::io::Read;
+use std::path::PathBuf;
+
+use crate::file::{
+    format::format_for_extension, source::FileSourceResult, FileSource, FileStoredFormat, Format,
+};
+
+/// Describes a set of files sourced from a glob pattern, e.g. `conf.d/*.toml`.
+///
+/// Unlike [`FileSourceFile`](super::file::FileSourceFile), which resolves to a
+/// single file, a pattern can match any number of files. Each match is
+/// resolved through the same extension-based format detection
+/// `FileSourceFile` uses, so in a directory of mixed-format drop-ins each
+/// file is parsed by its own extension; a match whose extension is not
+/// registered in `ALL_EXTENSIONS` is an error. Matches are returned in sorted
+/// filename order, so that e.g. `conf.d/20-local.toml` is layered over, and
+/// so overrides, `conf.d/10-base.toml` — the common `conf.d`-style drop-in
+/// configuration pattern.
+#[derive(Clone, Debug)]
+pub struct FileSourceGlob {
+    pattern: String,
+}
+
+impl FileSourceGlob {
+    pub fn new(pattern: impl Into<String>) -> FileSourceGlob {
+        FileSourceGlob {
+            pattern: pattern.into(),
+        }
+    }
+
+    fn resolve_one<F>(
+        path: PathBuf,
+        format_hint: &Option<F>,
+    ) -> Result<FileSourceResult, Box<dyn Error + Send + Sync>>
+    where
+        F: FileStoredFormat + Format + Clone + 'static,
+    {
+        let format = format_for_extension(&path, format_hint.as_ref())?;
+
+        let mut file = fs::File::open(&path)?;
+        let mut text = String::new();
+        file.read_to_string(&mut text)?;
+
+        Ok(FileSourceResult {
+            uri: Some(path.to_string_lossy().into_owned()),
+            content: text,
+            format,
+        })
+    }
+}
+
+impl<F> FileSource<F> for FileSourceGlob
+where
+    F: FileStoredFormat + Format + Clone + 'static,
+{
+    /// Resolves every file matching the pattern, in sorted filename order.
+    ///
+    /// A pattern that matches no files resolves to an empty list rather than
+    /// an error, so an optional glob source behaves the same as an optional
+    /// missing file. A per-entry error from the underlying walk (e.g.
+    /// permission denied on a directory) is surfaced rather than dropped, so
+    /// a file isn't silently missing from the merge with no indication why.
+    fn resolve(
+        &self,
+        format_hint: Option<F>,
+    ) -> Result<Vec<FileSourceResult>, Box<dyn Error + Send + Sync>> {
+        let mut paths: Vec<PathBuf> = glob::glob(&self.pattern)?.collect::<Result<_, _>>()?;
+        paths.retain(|path| path.is_file());
+        paths.sort();
+
+        paths
+            .into_iter()
+            .map(|path| Self::resolve_one(path, &format_hint))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::fs;
+
+    use super::FileSourceGlob;
+    use crate::file::{FileFormat, FileSource};
+    use crate::test_util::temp_dir;
+
+    #[test]
+    fn layers_matches_in_sorted_filename_order() {
+        let dir = temp_dir("glob-sorted");
+        fs::write(dir.join("20-local.json"), r#"{"b": 2}"#).unwrap();
+        fs::write(dir.join("10-base.json"), r#"{"a": 1}"#).unwrap();
+
+        let source = FileSourceGlob::new(format!("{}/*.json", dir.display()));
+        let results = FileSource::<FileFormat>::resolve(&source, None).unwrap();
+        let uris: Vec<_> = results.into_iter().map(|r| r.uri.unwrap()).collect();
+
+        assert!(uris[0].ends_with("10-base.json"));
+        assert!(uris[1].ends_with("20-local.json"));
+
+        fs::remove_dir_all(&dir).ok();
+    }
+
+    #[test]
+    fn non_matching_pattern_resolves_to_empty() {
+        let dir = temp_dir("glob-empty");
+
+        let source = FileSourceGlob::new(format!("{}/*.json", dir.display()));
+        let results = FileSource::<FileFormat>::resolve(&source, None).unwrap();
+
+        assert!(results.is_empty());
+
+        fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,12 @@
+pub(crate) mod file;
+pub(crate) mod glob;
+
+use crate::file::Format;
+
+/// The raw contents of a resolved configuration file, plus the [`Format`] to
+/// parse it with.
+pub(crate) struct FileSourceResult {
+    pub(crate) uri: Option<String>,
+    pub(crate) content: String,
+    pub(crate) format: Box<dyn Format>,
+}
@@ -0,0 +1,13 @@
+//! Test-only fixtures shared across the crate's `#[cfg(test)]` modules.
+
+use std::fs;
+use std::path::PathBuf;
+
+/// Creates (and returns) a uniquely-named scratch directory under the
+/// system temp dir, for tests that need real files on disk.
+pub(crate) fn temp_dir(name: &str) -> PathBuf {
+    let mut dir = std::env::temp_dir();
+    dir.push(format!("config-rs-test-{name}-{}", std::process::id()));
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
@@ -0,0 +1,111 @@
+use std::convert::TryFrom;
+use std::fmt;
+
+use crate::map::Map;
+
+/// Underlying kind of a configuration [`Value`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ValueKind {
+    Nil,
+    Boolean(bool),
+    Integer(i64),
+    /// An unsigned integer too large to fit in an `i64`.
+    ///
+    /// JSON (and other formats without a native integer width limit) can
+    /// produce values such as snowflake IDs or nanosecond timestamps that
+    /// exceed `i64::MAX`; this variant preserves them exactly rather than
+    /// falling back to a lossy `f64`.
+    UInteger(u64),
+    Float(f64),
+    String(String),
+    Table(Map<String, Value>),
+    Array(Vec<Value>),
+}
+
+impl fmt::Display for ValueKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match *self {
+            ValueKind::String(ref value) => write!(f, "{value}"),
+            ValueKind::Boolean(value) => write!(f, "{value}"),
+            ValueKind::Integer(value) => write!(f, "{value}"),
+            ValueKind::UInteger(value) => write!(f, "{value}"),
+            ValueKind::Float(value) => write!(f, "{value}"),
+            ValueKind::Nil => write!(f, "nil"),
+            ValueKind::Table(_) => write!(f, "a table"),
+            ValueKind::Array(_) => write!(f, "an array"),
+        }
+    }
+}
+
+/// A configuration value, tagged with the `uri` it was parsed from (if any)
+/// so that later error messages can point back at their source.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Value {
+    pub origin: Option<String>,
+    pub kind: ValueKind,
+}
+
+impl Value {
+    pub fn new(origin: Option<&String>, kind: ValueKind) -> Value {
+        Value {
+            origin: origin.cloned(),
+            kind,
+        }
+    }
+}
+
+impl TryFrom<Value> for i64 {
+    type Error = ValueKind;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value.kind {
+            ValueKind::Integer(value) => Ok(value),
+            ValueKind::UInteger(value) => {
+                i64::try_from(value).map_err(|_| ValueKind::UInteger(value))
+            }
+            kind => Err(kind),
+        }
+    }
+}
+
+impl TryFrom<Value> for u64 {
+    type Error = ValueKind;
+
+    fn try_from(value: Value) -> Result<Self, Self::Error> {
+        match value.kind {
+            ValueKind::UInteger(value) => Ok(value),
+            ValueKind::Integer(value) => {
+                u64::try_from(value).map_err(|_| ValueKind::Integer(value))
+            }
+            kind => Err(kind),
+        }
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::convert::TryFrom;
+
+    use super::{Value, ValueKind};
+
+    #[test]
+    fn uinteger_round_trips_through_u64() {
+        let value = Value::new(None, ValueKind::UInteger(u64::MAX));
+
+        assert_eq!(u64::try_from(value).unwrap(), u64::MAX);
+    }
+
+    #[test]
+    fn uinteger_too_large_for_i64_fails_to_convert() {
+        let value = Value::new(None, ValueKind::UInteger(u64::MAX));
+
+        assert_eq!(i64::try_from(value), Err(ValueKind::UInteger(u64::MAX)));
+    }
+
+    #[test]
+    fn integer_still_round_trips_through_i64() {
+        let value = Value::new(None, ValueKind::Integer(-1));
+
+        assert_eq!(i64::try_from(value).unwrap(), -1);
+    }
+}